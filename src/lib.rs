@@ -7,6 +7,26 @@ use std::string::FromUtf8Error;
 
 use thiserror::Error;
 
+/// The byte order used when reading or writing numeric values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endian {
+    /// Least significant byte first.
+    #[default]
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+/// Configuration for serialization and deserialization, currently limited to byte order.
+///
+/// The default configuration uses [`Endian::Little`], matching the behavior of the
+/// unconfigured `serialize_*`/`deserialize_*` functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SerializeConfig {
+    /// The byte order to use for numeric values and length prefixes.
+    pub endian: Endian,
+}
+
 /// An enumeration of errors that can occur during custom deserialization.
 #[derive(Debug, Error, PartialEq)]
 pub enum DeserializationError {
@@ -23,6 +43,81 @@ pub enum DeserializationError {
     /// Indicates a custom type could not be converted from raw parts.
     #[error("{0}")]
     InvalidValue(String),
+    /// Indicates a decoded count or length exceeded the configured `DeserializeLimits`.
+    #[error("Decoded value {1} exceeds limit {0}")]
+    LimitExceeded(usize, usize),
+}
+
+/// Limits on the element counts and byte lengths a deserialize function will trust from a
+/// buffer before allocating, guarding against hostile length prefixes.
+///
+/// The default imposes no limit, preserving the behavior of the unconfigured
+/// `deserialize_string`/`deserialize_vec` functions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeserializeLimits {
+    /// The maximum number of bytes a single string is allowed to claim.
+    pub max_bytes: usize,
+    /// The maximum number of elements a collection is allowed to claim.
+    pub max_elements: usize,
+}
+
+impl Default for DeserializeLimits {
+    fn default() -> Self {
+        DeserializeLimits {
+            max_bytes: usize::MAX,
+            max_elements: usize::MAX,
+        }
+    }
+}
+
+/// A cursor over a byte slice that reads fields without reallocating the remainder on every
+/// call, unlike the `Vec<u8>`-draining `deserialize_*` functions, which call `split_off` per field.
+///
+/// # Examples
+///
+/// ```
+/// let buffer = [1, 2, 3, 4];
+/// let mut reader = blt_utils::Reader::new(&buffer);
+/// assert_eq!(reader.read_bytes(2)?, [1, 2]);
+/// assert_eq!(reader.read_bytes(2)?, [3, 4]);
+/// assert!(reader.read_bytes(1).is_err());
+/// # Ok::<(), blt_utils::DeserializationError>(())
+/// ```
+pub struct Reader<'a> {
+    buffer: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    /// Creates a reader positioned at the start of the given buffer.
+    pub fn new(buffer: &'a [u8]) -> Self {
+        Reader {
+            buffer,
+            position: 0,
+        }
+    }
+
+    /// Reads and returns the next `n` bytes, advancing the cursor past them.
+    /// If fewer than `n` bytes remain, the cursor is left unchanged and an error is returned.
+    pub fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], DeserializationError> {
+        let remaining = self.remaining();
+        if n > remaining {
+            return Err(DeserializationError::UnexpectedByteCount(n, remaining));
+        }
+        let start = self.position;
+        self.position += n;
+        Ok(&self.buffer[start..self.position])
+    }
+
+    /// Returns the current position of the cursor within the buffer.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the number of bytes remaining after the cursor.
+    pub fn remaining(&self) -> usize {
+        self.buffer.len() - self.position
+    }
 }
 
 /// Appends the string representation of the given value to the buffer.
@@ -35,10 +130,27 @@ pub enum DeserializationError {
 /// assert_eq!(buffer.as_slice(), [12, 0, 0, 0, 0, 0, 0, 0, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33]);
 /// ```
 pub fn serialize_string<T: Into<String>>(value: T, buffer: &mut Vec<u8>) {
+    serialize_string_with(value, buffer, &SerializeConfig::default());
+}
+
+/// Appends the string representation of the given value to the buffer, using the byte order
+/// configured by `config` for the length prefix.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = Vec::new();
+/// let config = blt_utils::SerializeConfig { endian: blt_utils::Endian::Big };
+/// blt_utils::serialize_string_with("Hi", &mut buffer, &config);
+/// assert_eq!(buffer.as_slice(), [0, 0, 0, 0, 0, 0, 0, 2, 72, 105]);
+/// ```
+pub fn serialize_string_with<T: Into<String>>(
+    value: T,
+    buffer: &mut Vec<u8>,
+    config: &SerializeConfig,
+) {
     let mut value = value.into().into_bytes();
-    for b in value.len().to_le_bytes() {
-        buffer.push(b);
-    }
+    serialize_usize_with(value.len(), buffer, config);
     buffer.append(&mut value);
 }
 
@@ -58,16 +170,78 @@ pub fn deserialize_string<T: TryFrom<String>>(
 where
     <T as TryFrom<String>>::Error: ToString,
 {
-    let value_size = deserialize_usize(buffer)?;
-    if value_size > buffer.len() {
-        return Err(DeserializationError::UnexpectedByteCount(
+    deserialize_string_with(buffer, &SerializeConfig::default())
+}
+
+/// Removes the next string value from the buffer, using the byte order configured by `config`
+/// for the length prefix.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = [0, 0, 0, 0, 0, 0, 0, 2, 72, 105].to_vec();
+/// let config = blt_utils::SerializeConfig { endian: blt_utils::Endian::Big };
+/// let value = blt_utils::deserialize_string_with::<String>(&mut buffer, &config)?;
+/// assert_eq!(value, String::from("Hi"));
+/// # Ok::<(), blt_utils::DeserializationError>(())
+/// ```
+pub fn deserialize_string_with<T: TryFrom<String>>(
+    buffer: &mut Vec<u8>,
+    config: &SerializeConfig,
+) -> Result<T, DeserializationError>
+where
+    <T as TryFrom<String>>::Error: ToString,
+{
+    deserialize_string_with_limits(buffer, config, &DeserializeLimits::default())
+}
+
+/// Removes the next string value from the buffer, using the byte order configured by `config`
+/// for the length prefix and rejecting a decoded length over `limits.max_bytes` before
+/// allocating.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = [2, 0, 0, 0, 0, 0, 0, 0, 72, 105].to_vec();
+/// let config = blt_utils::SerializeConfig::default();
+/// let limits = blt_utils::DeserializeLimits { max_bytes: 1, max_elements: usize::MAX };
+/// let err = blt_utils::deserialize_string_with_limits::<String>(&mut buffer, &config, &limits)
+///     .unwrap_err();
+/// assert_eq!(err, blt_utils::DeserializationError::LimitExceeded(1, 2));
+/// ```
+pub fn deserialize_string_with_limits<T: TryFrom<String>>(
+    buffer: &mut Vec<u8>,
+    config: &SerializeConfig,
+    limits: &DeserializeLimits,
+) -> Result<T, DeserializationError>
+where
+    <T as TryFrom<String>>::Error: ToString,
+{
+    let mut reader = Reader::new(buffer.as_slice());
+    let result = deserialize_string_from_reader(&mut reader, config, limits);
+    *buffer = buffer.split_off(reader.position());
+    result
+}
+
+/// Reads the next string value from `reader`, using the byte order configured by `config` for
+/// the length prefix and rejecting a decoded length over `limits.max_bytes` before allocating.
+pub fn deserialize_string_from_reader<T: TryFrom<String>>(
+    reader: &mut Reader,
+    config: &SerializeConfig,
+    limits: &DeserializeLimits,
+) -> Result<T, DeserializationError>
+where
+    <T as TryFrom<String>>::Error: ToString,
+{
+    let value_size = deserialize_usize_from_reader(reader, config)?;
+    if value_size > limits.max_bytes {
+        return Err(DeserializationError::LimitExceeded(
+            limits.max_bytes,
             value_size,
-            buffer.len(),
         ));
     }
-    let tmp = buffer.split_off(value_size);
-    let result = String::from_utf8(buffer.to_owned()).map_err(|ex| ex.into());
-    *buffer = tmp;
+    let bytes = reader.read_bytes(value_size)?;
+    let result = String::from_utf8(bytes.to_vec()).map_err(|ex| ex.into());
     result.and_then(|value| {
         T::try_from(value).map_err(|ex| DeserializationError::InvalidValue(ex.to_string()))
     })
@@ -85,11 +259,30 @@ where
 /// assert_eq!(buffer.as_slice(), [2, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 72, 101, 108, 108, 111, 5, 0, 0, 0, 0, 0, 0, 0, 87, 111, 114, 108, 100]);
 /// ```
 pub fn serialize_vec<T: Into<String>>(value: Vec<T>, buffer: &mut Vec<u8>) {
-    for b in value.len().to_le_bytes() {
-        buffer.push(b);
-    }
+    serialize_vec_with(value, buffer, &SerializeConfig::default());
+}
+
+/// Appends the given collection to the buffer, using the byte order configured by `config`
+/// for the element count and each string's length prefix.
+///
+/// # Examples
+///
+/// ```
+/// let v = ["Hi"].to_vec();
+/// let mut buffer = Vec::new();
+/// let config = blt_utils::SerializeConfig { endian: blt_utils::Endian::Big };
+/// blt_utils::serialize_vec_with(v, &mut buffer, &config);
+///
+/// assert_eq!(buffer.as_slice(), [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 72, 105]);
+/// ```
+pub fn serialize_vec_with<T: Into<String>>(
+    value: Vec<T>,
+    buffer: &mut Vec<u8>,
+    config: &SerializeConfig,
+) {
+    serialize_usize_with(value.len(), buffer, config);
     for item in value {
-        serialize_string(item.into(), buffer);
+        serialize_string_with(item.into(), buffer, config);
     }
 }
 
@@ -110,10 +303,84 @@ pub fn deserialize_vec<T: TryFrom<String>>(
 where
     <T as TryFrom<String>>::Error: ToString,
 {
-    let num_items = deserialize_usize(buffer)?;
-    let mut result = Vec::with_capacity(num_items);
+    deserialize_vec_with(buffer, &SerializeConfig::default())
+}
+
+/// Removes the next collection of strings from the buffer, using the byte order configured by
+/// `config` for the element count and each string's length prefix.
+/// If an error occurs for an element after the first, the buffer is left in an indeterminate state.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2, 72, 105].to_vec();
+/// let config = blt_utils::SerializeConfig { endian: blt_utils::Endian::Big };
+/// let value = blt_utils::deserialize_vec_with::<String>(&mut buffer, &config)?;
+/// assert_eq!(value.as_slice(), [String::from("Hi")]);
+/// # Ok::<(), blt_utils::DeserializationError>(())
+/// ```
+pub fn deserialize_vec_with<T: TryFrom<String>>(
+    buffer: &mut Vec<u8>,
+    config: &SerializeConfig,
+) -> Result<Vec<T>, DeserializationError>
+where
+    <T as TryFrom<String>>::Error: ToString,
+{
+    deserialize_vec_with_limits(buffer, config, &DeserializeLimits::default())
+}
+
+/// Removes the next collection of strings from the buffer, using the byte order configured by
+/// `config` for the element count and each string's length prefix, rejecting an element count
+/// over `limits.max_elements` before allocating.
+/// If an error occurs for an element after the first, the buffer is left in an indeterminate state.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 72, 105].to_vec();
+/// let config = blt_utils::SerializeConfig::default();
+/// let limits = blt_utils::DeserializeLimits { max_bytes: usize::MAX, max_elements: 0 };
+/// let err = blt_utils::deserialize_vec_with_limits::<String>(&mut buffer, &config, &limits)
+///     .unwrap_err();
+/// assert_eq!(err, blt_utils::DeserializationError::LimitExceeded(0, 1));
+/// ```
+pub fn deserialize_vec_with_limits<T: TryFrom<String>>(
+    buffer: &mut Vec<u8>,
+    config: &SerializeConfig,
+    limits: &DeserializeLimits,
+) -> Result<Vec<T>, DeserializationError>
+where
+    <T as TryFrom<String>>::Error: ToString,
+{
+    let mut reader = Reader::new(buffer.as_slice());
+    let result = deserialize_vec_from_reader(&mut reader, config, limits);
+    *buffer = buffer.split_off(reader.position());
+    result
+}
+
+/// Reads the next collection of strings from `reader`, using the byte order configured by
+/// `config` for the element count and each string's length prefix, rejecting an element count
+/// over `limits.max_elements` before allocating.
+pub fn deserialize_vec_from_reader<T: TryFrom<String>>(
+    reader: &mut Reader,
+    config: &SerializeConfig,
+    limits: &DeserializeLimits,
+) -> Result<Vec<T>, DeserializationError>
+where
+    <T as TryFrom<String>>::Error: ToString,
+{
+    let num_items = deserialize_usize_from_reader(reader, config)?;
+    if num_items > limits.max_elements {
+        return Err(DeserializationError::LimitExceeded(
+            limits.max_elements,
+            num_items,
+        ));
+    }
+    let min_element_size = std::mem::size_of::<usize>().max(1);
+    let capacity = num_items.min(reader.remaining() / min_element_size);
+    let mut result = Vec::with_capacity(capacity);
     for _ in 0..num_items {
-        result.push(deserialize_string(buffer)?);
+        result.push(deserialize_string_from_reader(reader, config, limits)?);
     }
     Ok(result)
 }
@@ -133,12 +400,521 @@ where
 /// assert_eq!(buffer.as_slice(), [33, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 70, 105, 114, 115, 116, 4, 0, 0, 0, 0, 0, 0, 0, 76, 97, 115, 116, 42, 0, 0, 0, 0, 0, 0, 0]);
 /// ```
 pub fn finalize_serialization(buffer: &mut Vec<u8>) {
-    let buffer_len = buffer.len();
-    for (index, b) in buffer_len.to_le_bytes().iter().enumerate() {
+    finalize_serialization_with(buffer, &SerializeConfig::default());
+}
+
+/// Prepends the length of the buffer to the buffer, using the byte order configured by `config`.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = [70, 105].to_vec();
+/// let config = blt_utils::SerializeConfig { endian: blt_utils::Endian::Big };
+/// blt_utils::finalize_serialization_with(&mut buffer, &config);
+///
+/// assert_eq!(buffer.as_slice(), [0, 0, 0, 0, 0, 0, 0, 2, 70, 105]);
+/// ```
+pub fn finalize_serialization_with(buffer: &mut Vec<u8>, config: &SerializeConfig) {
+    let mut prefix = Vec::new();
+    serialize_usize_with(buffer.len(), &mut prefix, config);
+    for (index, b) in prefix.iter().enumerate() {
         buffer.insert(index, *b);
     }
 }
 
+/// Appends the LEB128 varint encoding of the given value to the buffer: the low 7 bits of
+/// each byte hold the value and the high bit is set on every byte but the last.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = Vec::new();
+/// blt_utils::serialize_varint_u64(300, &mut buffer);
+/// assert_eq!(buffer.as_slice(), [172, 2]);
+/// ```
+pub fn serialize_varint_u64(value: u64, buffer: &mut Vec<u8>) {
+    let mut value = value;
+    loop {
+        let mut byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value != 0 {
+            byte |= 0x80;
+        }
+        buffer.push(byte);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+/// Removes the next LEB128 varint value from the buffer.
+/// If the buffer runs out before a terminating byte is found, the buffer is unchanged and
+/// `UnexpectedByteCount` is returned. If the varint would overflow a `u64`, `InvalidValue` is returned.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = [172, 2].to_vec();
+/// let value = blt_utils::deserialize_varint_u64(&mut buffer)?;
+/// assert_eq!(value, 300);
+/// # Ok::<(), blt_utils::DeserializationError>(())
+/// ```
+pub fn deserialize_varint_u64(buffer: &mut Vec<u8>) -> Result<u64, DeserializationError> {
+    let mut reader = Reader::new(buffer.as_slice());
+    let result = deserialize_varint_u64_from_reader(&mut reader)?;
+    *buffer = buffer.split_off(reader.position());
+    Ok(result)
+}
+
+/// Reads the next LEB128 varint value from `reader`.
+/// If the varint would overflow a `u64`, `InvalidValue` is returned.
+pub fn deserialize_varint_u64_from_reader(
+    reader: &mut Reader,
+) -> Result<u64, DeserializationError> {
+    let mut result: u64 = 0;
+    let mut shift: u32 = 0;
+    loop {
+        if shift >= 64 {
+            return Err(DeserializationError::InvalidValue(String::from(
+                "varint overflows u64",
+            )));
+        }
+        let byte = reader.read_bytes(1)?[0];
+        let low = (byte & 0x7f) as u64;
+        if shift == 63 && low > 1 {
+            return Err(DeserializationError::InvalidValue(String::from(
+                "varint overflows u64",
+            )));
+        }
+        result |= low << shift;
+        if byte & 0x80 == 0 {
+            break;
+        }
+        shift += 7;
+    }
+    Ok(result)
+}
+
+/// Appends the zig-zag LEB128 varint encoding of the given value to the buffer, mapping
+/// signed values to unsigned ones so small-magnitude negatives stay compact.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = Vec::new();
+/// blt_utils::serialize_varint_i64(-150, &mut buffer);
+/// assert_eq!(buffer.as_slice(), [171, 2]);
+/// ```
+pub fn serialize_varint_i64(value: i64, buffer: &mut Vec<u8>) {
+    let zigzag = ((value << 1) ^ (value >> 63)) as u64;
+    serialize_varint_u64(zigzag, buffer);
+}
+
+/// Removes the next zig-zag LEB128 varint value from the buffer.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = [171, 2].to_vec();
+/// let value = blt_utils::deserialize_varint_i64(&mut buffer)?;
+/// assert_eq!(value, -150);
+/// # Ok::<(), blt_utils::DeserializationError>(())
+/// ```
+pub fn deserialize_varint_i64(buffer: &mut Vec<u8>) -> Result<i64, DeserializationError> {
+    let mut reader = Reader::new(buffer.as_slice());
+    let result = deserialize_varint_i64_from_reader(&mut reader);
+    *buffer = buffer.split_off(reader.position());
+    result
+}
+
+/// Reads the next zig-zag LEB128 varint value from `reader`.
+pub fn deserialize_varint_i64_from_reader(
+    reader: &mut Reader,
+) -> Result<i64, DeserializationError> {
+    let zigzag = deserialize_varint_u64_from_reader(reader)?;
+    Ok(((zigzag >> 1) as i64) ^ -((zigzag & 1) as i64))
+}
+
+/// Appends the string representation of the given value to the buffer, prefixing it with its
+/// length encoded as a LEB128 varint instead of a fixed-width `usize`.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = Vec::new();
+/// blt_utils::serialize_string_varint("Hello World!", &mut buffer);
+/// assert_eq!(buffer.as_slice(), [12, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33]);
+/// ```
+pub fn serialize_string_varint<T: Into<String>>(value: T, buffer: &mut Vec<u8>) {
+    let mut value = value.into().into_bytes();
+    serialize_varint_u64(value.len() as u64, buffer);
+    buffer.append(&mut value);
+}
+
+/// Removes the next varint-prefixed string value from the buffer.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = [12, 72, 101, 108, 108, 111, 32, 87, 111, 114, 108, 100, 33].to_vec();
+/// let value = blt_utils::deserialize_string_varint::<String>(&mut buffer)?;
+/// assert_eq!(value, String::from("Hello World!"));
+/// # Ok::<(), blt_utils::DeserializationError>(())
+/// ```
+pub fn deserialize_string_varint<T: TryFrom<String>>(
+    buffer: &mut Vec<u8>,
+) -> Result<T, DeserializationError>
+where
+    <T as TryFrom<String>>::Error: ToString,
+{
+    deserialize_string_varint_with_limits(buffer, &DeserializeLimits::default())
+}
+
+/// Removes the next varint-prefixed string value from the buffer, rejecting a decoded length
+/// over `limits.max_bytes` before allocating.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = [200, 1, 72, 105].to_vec();
+/// let limits = blt_utils::DeserializeLimits { max_bytes: 1, max_elements: usize::MAX };
+/// let err = blt_utils::deserialize_string_varint_with_limits::<String>(&mut buffer, &limits)
+///     .unwrap_err();
+/// assert_eq!(err, blt_utils::DeserializationError::LimitExceeded(1, 200));
+/// ```
+pub fn deserialize_string_varint_with_limits<T: TryFrom<String>>(
+    buffer: &mut Vec<u8>,
+    limits: &DeserializeLimits,
+) -> Result<T, DeserializationError>
+where
+    <T as TryFrom<String>>::Error: ToString,
+{
+    let mut reader = Reader::new(buffer.as_slice());
+    let result = deserialize_string_varint_from_reader(&mut reader, limits);
+    *buffer = buffer.split_off(reader.position());
+    result
+}
+
+/// Reads the next varint-prefixed string value from `reader`, rejecting a decoded length over
+/// `limits.max_bytes` before allocating.
+pub fn deserialize_string_varint_from_reader<T: TryFrom<String>>(
+    reader: &mut Reader,
+    limits: &DeserializeLimits,
+) -> Result<T, DeserializationError>
+where
+    <T as TryFrom<String>>::Error: ToString,
+{
+    let value_size = deserialize_varint_u64_from_reader(reader)? as usize;
+    if value_size > limits.max_bytes {
+        return Err(DeserializationError::LimitExceeded(
+            limits.max_bytes,
+            value_size,
+        ));
+    }
+    let bytes = reader.read_bytes(value_size)?;
+    let result = String::from_utf8(bytes.to_vec()).map_err(|ex| ex.into());
+    result.and_then(|value| {
+        T::try_from(value).map_err(|ex| DeserializationError::InvalidValue(ex.to_string()))
+    })
+}
+
+/// Appends the given collection to the buffer, prefixing the element count and each string's
+/// length with LEB128 varints instead of fixed-width `usize` values.
+///
+/// # Examples
+///
+/// ```
+/// let v = ["Hello", "World"].to_vec();
+/// let mut buffer = Vec::new();
+/// blt_utils::serialize_vec_varint(v, &mut buffer);
+///
+/// assert_eq!(buffer.as_slice(), [2, 5, 72, 101, 108, 108, 111, 5, 87, 111, 114, 108, 100]);
+/// ```
+pub fn serialize_vec_varint<T: Into<String>>(value: Vec<T>, buffer: &mut Vec<u8>) {
+    serialize_varint_u64(value.len() as u64, buffer);
+    for item in value {
+        serialize_string_varint(item.into(), buffer);
+    }
+}
+
+/// Removes the next varint-prefixed collection of strings from the buffer.
+/// If an error occurs for an element after the first, the buffer is left in an indeterminate state.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = [2, 5, 72, 101, 108, 108, 111, 5, 87, 111, 114, 108, 100].to_vec();
+/// let value = blt_utils::deserialize_vec_varint::<String>(&mut buffer)?;
+/// assert_eq!(value.as_slice(), [String::from("Hello"), String::from("World")]);
+/// # Ok::<(), blt_utils::DeserializationError>(())
+/// ```
+pub fn deserialize_vec_varint<T: TryFrom<String>>(
+    buffer: &mut Vec<u8>,
+) -> Result<Vec<T>, DeserializationError>
+where
+    <T as TryFrom<String>>::Error: ToString,
+{
+    deserialize_vec_varint_with_limits(buffer, &DeserializeLimits::default())
+}
+
+/// Removes the next varint-prefixed collection of strings from the buffer, rejecting an element
+/// count over `limits.max_elements` before allocating.
+/// If an error occurs for an element after the first, the buffer is left in an indeterminate state.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = [2, 5, 72, 101, 108, 108, 111, 5, 87, 111, 114, 108, 100].to_vec();
+/// let limits = blt_utils::DeserializeLimits { max_bytes: usize::MAX, max_elements: 1 };
+/// let err = blt_utils::deserialize_vec_varint_with_limits::<String>(&mut buffer, &limits)
+///     .unwrap_err();
+/// assert_eq!(err, blt_utils::DeserializationError::LimitExceeded(1, 2));
+/// ```
+pub fn deserialize_vec_varint_with_limits<T: TryFrom<String>>(
+    buffer: &mut Vec<u8>,
+    limits: &DeserializeLimits,
+) -> Result<Vec<T>, DeserializationError>
+where
+    <T as TryFrom<String>>::Error: ToString,
+{
+    let mut reader = Reader::new(buffer.as_slice());
+    let result = deserialize_vec_varint_from_reader(&mut reader, limits);
+    *buffer = buffer.split_off(reader.position());
+    result
+}
+
+/// Reads the next varint-prefixed collection of strings from `reader`, rejecting an element
+/// count over `limits.max_elements` before allocating.
+pub fn deserialize_vec_varint_from_reader<T: TryFrom<String>>(
+    reader: &mut Reader,
+    limits: &DeserializeLimits,
+) -> Result<Vec<T>, DeserializationError>
+where
+    <T as TryFrom<String>>::Error: ToString,
+{
+    let num_items = deserialize_varint_u64_from_reader(reader)? as usize;
+    if num_items > limits.max_elements {
+        return Err(DeserializationError::LimitExceeded(
+            limits.max_elements,
+            num_items,
+        ));
+    }
+    let capacity = num_items.min(reader.remaining());
+    let mut result = Vec::with_capacity(capacity);
+    for _ in 0..num_items {
+        result.push(deserialize_string_varint_from_reader(reader, limits)?);
+    }
+    Ok(result)
+}
+
+/// Prepends the length of the buffer to the buffer, encoded as a LEB128 varint instead of a
+/// fixed-width `usize`.
+///
+/// # Examples
+///
+/// ```
+/// let mut buffer = [5, 72, 105].to_vec();
+/// blt_utils::finalize_serialization_varint(&mut buffer);
+///
+/// assert_eq!(buffer.as_slice(), [3, 5, 72, 105]);
+/// ```
+pub fn finalize_serialization_varint(buffer: &mut Vec<u8>) {
+    let mut prefix = Vec::new();
+    serialize_varint_u64(buffer.len() as u64, &mut prefix);
+    for (index, b) in prefix.iter().enumerate() {
+        buffer.insert(index, *b);
+    }
+}
+
+blt_macros::add_compact_unsigned!(u8, "u8");
+blt_macros::add_compact_unsigned!(u16, "u16");
+blt_macros::add_compact_unsigned!(u32, "u32");
+blt_macros::add_compact_unsigned!(u64, "u64");
+blt_macros::add_compact_unsigned!(u128, "u128");
+blt_macros::add_compact_unsigned!(usize, "usize");
+
+blt_macros::add_compact_signed!(i8, "i8");
+blt_macros::add_compact_signed!(i16, "i16");
+blt_macros::add_compact_signed!(i32, "i32");
+blt_macros::add_compact_signed!(i64, "i64");
+blt_macros::add_compact_signed!(i128, "i128");
+blt_macros::add_compact_signed!(isize, "isize");
+
+blt_macros::remove_compact_unsigned!(u8, "u8");
+blt_macros::remove_compact_unsigned!(u16, "u16");
+blt_macros::remove_compact_unsigned!(u32, "u32");
+blt_macros::remove_compact_unsigned!(u64, "u64");
+blt_macros::remove_compact_unsigned!(u128, "u128");
+blt_macros::remove_compact_unsigned!(usize, "usize");
+
+blt_macros::remove_compact_signed!(i8, "i8");
+blt_macros::remove_compact_signed!(i16, "i16");
+blt_macros::remove_compact_signed!(i32, "i32");
+blt_macros::remove_compact_signed!(i64, "i64");
+blt_macros::remove_compact_signed!(i128, "i128");
+blt_macros::remove_compact_signed!(isize, "isize");
+
+/// A type that knows how to append its own serialized representation to a buffer.
+///
+/// This is the trait-based counterpart to the free `serialize_*` functions: implementing it
+/// lets a value be nested inside a `Vec<T>` or a hand-written struct/enum impl without the
+/// caller needing to know which free function to call for each field.
+///
+/// For a struct, implement this by calling `serialize` on each field in declaration order.
+/// For an enum, write a discriminant first (e.g. the variant index as a `u32`), followed by
+/// the variant's fields:
+///
+/// ```
+/// use blt_utils::{Deserialize, Serialize};
+///
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// impl blt_utils::Serialize for Point {
+///     fn serialize_with(&self, buffer: &mut Vec<u8>, config: &blt_utils::SerializeConfig) {
+///         self.x.serialize_with(buffer, config);
+///         self.y.serialize_with(buffer, config);
+///     }
+/// }
+///
+/// impl blt_utils::Deserialize for Point {
+///     fn deserialize_from_reader(
+///         reader: &mut blt_utils::Reader,
+///         config: &blt_utils::SerializeConfig,
+///         limits: &blt_utils::DeserializeLimits,
+///     ) -> Result<Self, blt_utils::DeserializationError> {
+///         Ok(Point {
+///             x: i32::deserialize_from_reader(reader, config, limits)?,
+///             y: i32::deserialize_from_reader(reader, config, limits)?,
+///         })
+///     }
+/// }
+///
+/// let mut buffer = Vec::new();
+/// Point { x: 1, y: 2 }.serialize(&mut buffer);
+/// let point = Point::deserialize(&mut buffer)?;
+/// assert_eq!((point.x, point.y), (1, 2));
+/// # Ok::<(), blt_utils::DeserializationError>(())
+/// ```
+///
+/// Writing the impls above by hand for every struct and enum gets repetitive, so
+/// [`impl_serde_struct!`] and [`impl_serde_enum!`] generate them from a short field list instead
+/// (this crate has no `proc-macro = true` crate to hang a real `#[derive(...)]` off of, so these
+/// are `macro_rules!` invocations rather than derives, but they cover the same struct/enum cases).
+pub trait Serialize {
+    /// Appends this value's serialized representation to the buffer, using the byte order
+    /// configured by `config`.
+    fn serialize_with(&self, buffer: &mut Vec<u8>, config: &SerializeConfig);
+
+    /// Appends this value's serialized representation to the buffer.
+    fn serialize(&self, buffer: &mut Vec<u8>) {
+        self.serialize_with(buffer, &SerializeConfig::default());
+    }
+}
+
+/// A type that knows how to remove its own serialized representation from a buffer.
+///
+/// This is the trait-based counterpart to the free `deserialize_*` functions. See
+/// [`Serialize`] for the struct/enum implementation pattern this trait is meant to pair with.
+///
+/// Implementations read from the shared [`Reader`] cursor in [`deserialize_from_reader`], rather
+/// than draining a `Vec<u8>` one field at a time: a struct with many fields reads every field off
+/// the same cursor, the same way the free `deserialize_*_from_reader` functions do, instead of
+/// paying a `Vec::split_off` copy per field.
+///
+/// [`deserialize_from_reader`]: Deserialize::deserialize_from_reader
+pub trait Deserialize: Sized {
+    /// Reads this value's serialized representation from `reader`, using the byte order
+    /// configured by `config`, rejecting any decoded element count or byte length over `limits`
+    /// before allocating.
+    fn deserialize_from_reader(
+        reader: &mut Reader,
+        config: &SerializeConfig,
+        limits: &DeserializeLimits,
+    ) -> Result<Self, DeserializationError>;
+
+    /// Removes this value's serialized representation from the buffer.
+    fn deserialize(buffer: &mut Vec<u8>) -> Result<Self, DeserializationError> {
+        Self::deserialize_with(buffer, &SerializeConfig::default())
+    }
+
+    /// Removes this value's serialized representation from the buffer, using the byte order
+    /// configured by `config`.
+    fn deserialize_with(
+        buffer: &mut Vec<u8>,
+        config: &SerializeConfig,
+    ) -> Result<Self, DeserializationError> {
+        Self::deserialize_with_limits(buffer, config, &DeserializeLimits::default())
+    }
+
+    /// Removes this value's serialized representation from the buffer, using the byte order
+    /// configured by `config`, rejecting any decoded element count or byte length over `limits`
+    /// before allocating.
+    fn deserialize_with_limits(
+        buffer: &mut Vec<u8>,
+        config: &SerializeConfig,
+        limits: &DeserializeLimits,
+    ) -> Result<Self, DeserializationError> {
+        let mut reader = Reader::new(buffer.as_slice());
+        let result = Self::deserialize_from_reader(&mut reader, config, limits);
+        *buffer = buffer.split_off(reader.position());
+        result
+    }
+}
+
+impl Serialize for String {
+    fn serialize_with(&self, buffer: &mut Vec<u8>, config: &SerializeConfig) {
+        serialize_string_with(self.clone(), buffer, config);
+    }
+}
+
+impl Deserialize for String {
+    fn deserialize_from_reader(
+        reader: &mut Reader,
+        config: &SerializeConfig,
+        limits: &DeserializeLimits,
+    ) -> Result<Self, DeserializationError> {
+        deserialize_string_from_reader(reader, config, limits)
+    }
+}
+
+impl<T: Serialize> Serialize for Vec<T> {
+    fn serialize_with(&self, buffer: &mut Vec<u8>, config: &SerializeConfig) {
+        serialize_usize_with(self.len(), buffer, config);
+        for item in self {
+            item.serialize_with(buffer, config);
+        }
+    }
+}
+
+impl<T: Deserialize> Deserialize for Vec<T> {
+    fn deserialize_from_reader(
+        reader: &mut Reader,
+        config: &SerializeConfig,
+        limits: &DeserializeLimits,
+    ) -> Result<Self, DeserializationError> {
+        let num_items = deserialize_usize_from_reader(reader, config)?;
+        if num_items > limits.max_elements {
+            return Err(DeserializationError::LimitExceeded(
+                limits.max_elements,
+                num_items,
+            ));
+        }
+        // `T`'s minimum encoded size isn't known at this generic bound, so clamp against the
+        // remaining byte count rather than a per-element size the way `deserialize_vec_from_reader`
+        // does for strings; this still turns a hostile huge count into at most one
+        // reader-sized allocation instead of an unbounded one.
+        let capacity = num_items.min(reader.remaining());
+        let mut result = Vec::with_capacity(capacity);
+        for _ in 0..num_items {
+            result.push(T::deserialize_from_reader(reader, config, limits)?);
+        }
+        Ok(result)
+    }
+}
+
 blt_macros::add_num!(u64, "u8");
 blt_macros::add_num!(u64, "u16");
 blt_macros::add_num!(u64, "u32");
@@ -173,16 +949,40 @@ blt_macros::remove_num!(isize, "isize");
 blt_macros::remove_num!(f32, "f32");
 blt_macros::remove_num!(f64, "f64");
 
+blt_macros::impl_serde_num_widened!(u8, u64, "u8");
+blt_macros::impl_serde_num_widened!(u16, u64, "u16");
+blt_macros::impl_serde_num_widened!(u32, u64, "u32");
+blt_macros::impl_serde_num_direct!(u64, "u64");
+blt_macros::impl_serde_num_compact!(u128, "u128");
+blt_macros::impl_serde_num_direct!(usize, "usize");
+
+blt_macros::impl_serde_num_widened!(i8, i64, "i8");
+blt_macros::impl_serde_num_widened!(i16, i64, "i16");
+blt_macros::impl_serde_num_widened!(i32, i64, "i32");
+blt_macros::impl_serde_num_direct!(i64, "i64");
+blt_macros::impl_serde_num_compact!(i128, "i128");
+blt_macros::impl_serde_num_direct!(isize, "isize");
+
+blt_macros::impl_serde_num_direct!(f32, "f32");
+blt_macros::impl_serde_num_direct!(f64, "f64");
+
 #[macro_use]
 mod blt_macros {
     macro_rules! add_num {
         ($t: ty, $t_name: expr) => {
             paste::paste! {
-                /// Adds the given numeric value to the buffer.
+                /// Adds the given numeric value to the buffer using little-endian byte order.
                 pub fn [<serialize_ $t_name>](value: $t, buffer: &mut Vec<u8>) {
-                    for b in value.to_le_bytes() {
-                        buffer.push(b);
-                    }
+                    [<serialize_ $t_name _with>](value, buffer, &SerializeConfig::default());
+                }
+
+                /// Adds the given numeric value to the buffer, using the byte order configured by `config`.
+                pub fn [<serialize_ $t_name _with>](value: $t, buffer: &mut Vec<u8>, config: &SerializeConfig) {
+                    let bytes = match config.endian {
+                        Endian::Little => value.to_le_bytes(),
+                        Endian::Big => value.to_be_bytes(),
+                    };
+                    buffer.extend_from_slice(&bytes);
                 }
             }
         };
@@ -191,25 +991,415 @@ mod blt_macros {
     macro_rules! remove_num {
         ($t: ty, $t_name: expr) => {
             paste::paste! {
-                /// Removes the next numeric value from the buffer.
+                /// Removes the next numeric value from the buffer using little-endian byte order.
                 /// If the buffer does not contain enough elements to create a numeric value, the buffer is unchanged and an error is returned.
                 pub fn [<deserialize_ $t_name>](buffer: &mut Vec<u8>) -> Result<$t, DeserializationError> {
-                    let t_len = std::mem::size_of::<$t>();
-                    if t_len > buffer.len() {
-                        return Err(DeserializationError::UnexpectedByteCount(
-                            t_len,
-                            buffer.len(),
-                        ));
+                    [<deserialize_ $t_name _with>](buffer, &SerializeConfig::default())
+                }
+
+                /// Removes the next numeric value from the buffer, using the byte order configured by `config`.
+                /// If the buffer does not contain enough elements to create a numeric value, the buffer is unchanged and an error is returned.
+                pub fn [<deserialize_ $t_name _with>](buffer: &mut Vec<u8>, config: &SerializeConfig) -> Result<$t, DeserializationError> {
+                    let mut reader = Reader::new(buffer.as_slice());
+                    let result = [<deserialize_ $t_name _from_reader>](&mut reader, config)?;
+                    *buffer = buffer.split_off(reader.position());
+                    Ok(result)
+                }
+
+                /// Reads the next numeric value from `reader`, using the byte order configured by `config`.
+                pub fn [<deserialize_ $t_name _from_reader>](reader: &mut Reader, config: &SerializeConfig) -> Result<$t, DeserializationError> {
+                    let bytes = reader.read_bytes(std::mem::size_of::<$t>())?;
+                    let result = match config.endian {
+                        Endian::Little => $t::from_le_bytes(bytes.try_into().unwrap()),
+                        Endian::Big => $t::from_be_bytes(bytes.try_into().unwrap()),
+                    };
+                    Ok(result)
+                }
+            }
+        };
+    }
+
+    /// Implements `Serialize`/`Deserialize` for a type whose `serialize_*`/`deserialize_*`
+    /// functions already operate on that exact type.
+    macro_rules! impl_serde_num_direct {
+        ($t: ty, $t_name: expr) => {
+            paste::paste! {
+                impl crate::Serialize for $t {
+                    fn serialize_with(&self, buffer: &mut Vec<u8>, config: &SerializeConfig) {
+                        crate::[<serialize_ $t_name _with>](*self, buffer, config);
+                    }
+                }
+
+                impl crate::Deserialize for $t {
+                    fn deserialize_from_reader(
+                        reader: &mut Reader,
+                        config: &SerializeConfig,
+                        _limits: &DeserializeLimits,
+                    ) -> Result<Self, DeserializationError> {
+                        crate::[<deserialize_ $t_name _from_reader>](reader, config)
                     }
-                    let remaining_bytes = buffer.split_off(t_len);
-                    let result = $t::from_le_bytes(buffer.as_slice().try_into().unwrap());
-                    *buffer = remaining_bytes;
+                }
+            }
+        };
+    }
+
+    /// Implements `Serialize`/`Deserialize` for a type whose `serialize_*`/`deserialize_*`
+    /// functions operate on the wider `$wide` type shared by its size group (see `add_num!`).
+    macro_rules! impl_serde_num_widened {
+        ($t: ty, $wide: ty, $t_name: expr) => {
+            paste::paste! {
+                impl crate::Serialize for $t {
+                    fn serialize_with(&self, buffer: &mut Vec<u8>, config: &SerializeConfig) {
+                        crate::[<serialize_ $t_name _with>](*self as $wide, buffer, config);
+                    }
+                }
+
+                impl crate::Deserialize for $t {
+                    fn deserialize_from_reader(
+                        reader: &mut Reader,
+                        config: &SerializeConfig,
+                        _limits: &DeserializeLimits,
+                    ) -> Result<Self, DeserializationError> {
+                        crate::[<deserialize_ $t_name _from_reader>](reader, config)
+                            .map(|value| value as $t)
+                    }
+                }
+            }
+        };
+    }
+
+    /// Implements `Serialize`/`Deserialize` for a type whose `serialize_*`/`deserialize_*`
+    /// functions are only defined up to `u64`/`i64` (see `add_num!`'s size-group quirk), so going
+    /// through them would silently truncate the value instead of erroring. Routes through the
+    /// compact encoding's `serialize_compact_*`/`deserialize_compact_*_from_reader` functions
+    /// instead, which operate on the type's own full width. The compact encoding has no
+    /// configurable byte order of its own, so `config` is accepted for trait compatibility but
+    /// otherwise unused.
+    macro_rules! impl_serde_num_compact {
+        ($t: ty, $t_name: expr) => {
+            paste::paste! {
+                impl crate::Serialize for $t {
+                    fn serialize_with(&self, buffer: &mut Vec<u8>, _config: &SerializeConfig) {
+                        crate::[<serialize_compact_ $t_name>](*self, buffer);
+                    }
+                }
+
+                impl crate::Deserialize for $t {
+                    fn deserialize_from_reader(
+                        reader: &mut Reader,
+                        _config: &SerializeConfig,
+                        _limits: &DeserializeLimits,
+                    ) -> Result<Self, DeserializationError> {
+                        crate::[<deserialize_compact_ $t_name _from_reader>](reader)
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! add_compact_unsigned {
+        ($t: ty, $t_name: expr) => {
+            paste::paste! {
+                /// Appends the given value to the buffer as a single length byte (the number of
+                /// significant little-endian bytes) followed by exactly that many bytes, with
+                /// leading zero bytes stripped.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// let mut buffer = Vec::new();
+                #[doc = concat!("blt_utils::serialize_compact_", $t_name, "(5, &mut buffer);")]
+                /// assert_eq!(buffer.as_slice(), [1, 5]);
+                /// ```
+                pub fn [<serialize_compact_ $t_name>](value: $t, buffer: &mut Vec<u8>) {
+                    let bytes = value.to_le_bytes();
+                    let mut len = bytes.len();
+                    while len > 0 && bytes[len - 1] == 0 {
+                        len -= 1;
+                    }
+                    buffer.push(len as u8);
+                    buffer.extend_from_slice(&bytes[..len]);
+                }
+            }
+        };
+    }
+
+    macro_rules! remove_compact_unsigned {
+        ($t: ty, $t_name: expr) => {
+            paste::paste! {
+                /// Reads the next compact-encoded value from `reader`, zero-extending the
+                /// retained bytes back to the full width of the type.
+                pub fn [<deserialize_compact_ $t_name _from_reader>](
+                    reader: &mut Reader,
+                ) -> Result<$t, DeserializationError> {
+                    let len = reader.read_bytes(1)?[0] as usize;
+                    let width = std::mem::size_of::<$t>();
+                    if len > width {
+                        return Err(DeserializationError::InvalidValue(format!(
+                            "compact {} length {len} exceeds type width {width}",
+                            stringify!($t)
+                        )));
+                    }
+                    let value_bytes = reader.read_bytes(len)?;
+                    let mut full = [0u8; std::mem::size_of::<$t>()];
+                    full[..len].copy_from_slice(value_bytes);
+                    Ok($t::from_le_bytes(full))
+                }
+
+                /// Removes the next compact-encoded value from the buffer.
+                /// If the buffer does not contain enough elements, the buffer is unchanged and an error is returned.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// let mut buffer = [1, 5].to_vec();
+                #[doc = concat!(
+                    "let value = blt_utils::deserialize_compact_", $t_name, "(&mut buffer)?;"
+                )]
+                /// assert_eq!(value, 5);
+                /// # Ok::<(), blt_utils::DeserializationError>(())
+                /// ```
+                pub fn [<deserialize_compact_ $t_name>](
+                    buffer: &mut Vec<u8>,
+                ) -> Result<$t, DeserializationError> {
+                    let mut reader = Reader::new(buffer.as_slice());
+                    let result = [<deserialize_compact_ $t_name _from_reader>](&mut reader)?;
+                    *buffer = buffer.split_off(reader.position());
                     Ok(result)
                 }
             }
         };
     }
 
+    macro_rules! add_compact_signed {
+        ($t: ty, $t_name: expr) => {
+            paste::paste! {
+                /// Appends the given value to the buffer as a single length byte followed by
+                /// exactly that many little-endian bytes, with the minimal number of leading
+                /// bytes kept that still let the value be recovered by sign-extending the most
+                /// significant retained byte.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// let mut buffer = Vec::new();
+                #[doc = concat!("blt_utils::serialize_compact_", $t_name, "(-5, &mut buffer);")]
+                /// assert_eq!(buffer.as_slice(), [1, 251]);
+                /// ```
+                pub fn [<serialize_compact_ $t_name>](value: $t, buffer: &mut Vec<u8>) {
+                    let bytes = value.to_le_bytes();
+                    let mut len = bytes.len();
+                    while len > 1 {
+                        let msb = bytes[len - 1];
+                        let next = bytes[len - 2];
+                        let zero_redundant = msb == 0x00 && next & 0x80 == 0;
+                        let ones_redundant = msb == 0xff && next & 0x80 != 0;
+                        if zero_redundant || ones_redundant {
+                            len -= 1;
+                        } else {
+                            break;
+                        }
+                    }
+                    buffer.push(len as u8);
+                    buffer.extend_from_slice(&bytes[..len]);
+                }
+            }
+        };
+    }
+
+    macro_rules! remove_compact_signed {
+        ($t: ty, $t_name: expr) => {
+            paste::paste! {
+                /// Reads the next compact-encoded value from `reader`, sign-extending based on
+                /// the top bit of the most significant retained byte back to the full width of
+                /// the type.
+                pub fn [<deserialize_compact_ $t_name _from_reader>](
+                    reader: &mut Reader,
+                ) -> Result<$t, DeserializationError> {
+                    let len = reader.read_bytes(1)?[0] as usize;
+                    let width = std::mem::size_of::<$t>();
+                    if len == 0 || len > width {
+                        return Err(DeserializationError::InvalidValue(format!(
+                            "compact {} length {len} is invalid for type width {width}",
+                            stringify!($t)
+                        )));
+                    }
+                    let value_bytes = reader.read_bytes(len)?;
+                    let fill = if value_bytes[len - 1] & 0x80 != 0 {
+                        0xff
+                    } else {
+                        0x00
+                    };
+                    let mut full = [fill; std::mem::size_of::<$t>()];
+                    full[..len].copy_from_slice(value_bytes);
+                    Ok($t::from_le_bytes(full))
+                }
+
+                /// Removes the next compact-encoded value from the buffer.
+                /// If the buffer does not contain enough elements, the buffer is unchanged and an error is returned.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// let mut buffer = [1, 251].to_vec();
+                #[doc = concat!(
+                    "let value = blt_utils::deserialize_compact_", $t_name, "(&mut buffer)?;"
+                )]
+                /// assert_eq!(value, -5);
+                /// # Ok::<(), blt_utils::DeserializationError>(())
+                /// ```
+                pub fn [<deserialize_compact_ $t_name>](
+                    buffer: &mut Vec<u8>,
+                ) -> Result<$t, DeserializationError> {
+                    let mut reader = Reader::new(buffer.as_slice());
+                    let result = [<deserialize_compact_ $t_name _from_reader>](&mut reader)?;
+                    *buffer = buffer.split_off(reader.position());
+                    Ok(result)
+                }
+            }
+        };
+    }
+
+    /// Implements [`Serialize`](crate::Serialize) and [`Deserialize`](crate::Deserialize) for a
+    /// struct by forwarding each named field, in declaration order, to that field's own impl.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// blt_utils::impl_serde_struct!(Point { x, y });
+    ///
+    /// let mut buffer = Vec::new();
+    /// blt_utils::Serialize::serialize(&Point { x: 1, y: 2 }, &mut buffer);
+    /// let point = <Point as blt_utils::Deserialize>::deserialize(&mut buffer)?;
+    /// assert_eq!((point.x, point.y), (1, 2));
+    /// # Ok::<(), blt_utils::DeserializationError>(())
+    /// ```
+    ///
+    /// Each field is forwarded through [`serialize_with`](crate::Serialize::serialize_with) /
+    /// [`deserialize_from_reader`](crate::Deserialize::deserialize_from_reader), so a [`SerializeConfig`]
+    /// passed to the struct is honored by every field:
+    ///
+    /// ```
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    ///
+    /// blt_utils::impl_serde_struct!(Point { x, y });
+    ///
+    /// let config = blt_utils::SerializeConfig { endian: blt_utils::Endian::Big };
+    /// let mut buffer = Vec::new();
+    /// blt_utils::Serialize::serialize_with(&Point { x: 1, y: 2 }, &mut buffer, &config);
+    /// let point = <Point as blt_utils::Deserialize>::deserialize_with(&mut buffer, &config)?;
+    /// assert_eq!((point.x, point.y), (1, 2));
+    /// # Ok::<(), blt_utils::DeserializationError>(())
+    /// ```
+    #[macro_export]
+    macro_rules! impl_serde_struct {
+        ($name:ident { $($field:ident),+ $(,)? }) => {
+            impl $crate::Serialize for $name {
+                fn serialize_with(&self, buffer: &mut Vec<u8>, config: &$crate::SerializeConfig) {
+                    $( $crate::Serialize::serialize_with(&self.$field, buffer, config); )+
+                }
+            }
+
+            impl $crate::Deserialize for $name {
+                fn deserialize_from_reader(
+                    reader: &mut $crate::Reader,
+                    config: &$crate::SerializeConfig,
+                    limits: &$crate::DeserializeLimits,
+                ) -> Result<Self, $crate::DeserializationError> {
+                    Ok($name {
+                        $( $field: $crate::Deserialize::deserialize_from_reader(reader, config, limits)?, )+
+                    })
+                }
+            }
+        };
+    }
+
+    /// Implements [`Serialize`](crate::Serialize) and [`Deserialize`](crate::Deserialize) for an
+    /// enum whose variants have named fields (or none), using a leading `u32` discriminant to
+    /// select the variant on deserialize. An unrecognized discriminant deserializes to
+    /// [`InvalidValue`](crate::DeserializationError::InvalidValue).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// enum Shape {
+    ///     Point,
+    ///     Circle { radius: f64 },
+    /// }
+    ///
+    /// blt_utils::impl_serde_enum!(Shape {
+    ///     0 => Point,
+    ///     1 => Circle { radius },
+    /// });
+    ///
+    /// let mut buffer = Vec::new();
+    /// blt_utils::Serialize::serialize(&Shape::Circle { radius: 2.5 }, &mut buffer);
+    /// match <Shape as blt_utils::Deserialize>::deserialize(&mut buffer)? {
+    ///     Shape::Circle { radius } => assert_eq!(radius, 2.5),
+    ///     Shape::Point => panic!("expected Circle"),
+    /// }
+    /// # Ok::<(), blt_utils::DeserializationError>(())
+    /// ```
+    #[macro_export]
+    macro_rules! impl_serde_enum {
+        (
+            $name:ident {
+                $( $disc:literal => $variant:ident $( { $($field:ident),+ $(,)? } )? ),+ $(,)?
+            }
+        ) => {
+            impl $crate::Serialize for $name {
+                fn serialize_with(&self, buffer: &mut Vec<u8>, config: &$crate::SerializeConfig) {
+                    match self {
+                        $(
+                            $name::$variant $( { $($field),+ } )? => {
+                                <u32 as $crate::Serialize>::serialize_with(&$disc, buffer, config);
+                                $( $( $crate::Serialize::serialize_with($field, buffer, config); )+ )?
+                            }
+                        )+
+                    }
+                }
+            }
+
+            impl $crate::Deserialize for $name {
+                fn deserialize_from_reader(
+                    reader: &mut $crate::Reader,
+                    config: &$crate::SerializeConfig,
+                    limits: &$crate::DeserializeLimits,
+                ) -> Result<Self, $crate::DeserializationError> {
+                    let discriminant =
+                        <u32 as $crate::Deserialize>::deserialize_from_reader(reader, config, limits)?;
+                    match discriminant {
+                        $(
+                            $disc => Ok($name::$variant $( {
+                                $( $field: $crate::Deserialize::deserialize_from_reader(reader, config, limits)?, )+
+                            } )? ),
+                        )+
+                        other => Err($crate::DeserializationError::InvalidValue(format!(
+                            "unknown {} discriminant {}",
+                            stringify!($name),
+                            other
+                        ))),
+                    }
+                }
+            }
+        };
+    }
+
+    pub(crate) use add_compact_signed;
+    pub(crate) use add_compact_unsigned;
     pub(crate) use add_num;
+    pub(crate) use impl_serde_num_compact;
+    pub(crate) use impl_serde_num_direct;
+    pub(crate) use impl_serde_num_widened;
+    pub(crate) use remove_compact_signed;
+    pub(crate) use remove_compact_unsigned;
     pub(crate) use remove_num;
 }